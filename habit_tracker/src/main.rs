@@ -1,3 +1,4 @@
+use chrono::Datelike;
 use clap::{Parser, Subcommand};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -8,7 +9,7 @@ use std::path::Path;
 const DATA_FILE: &str = "habits.json";
 
 /// A simple command-line habit tracker
-/// 
+///
 /// This program helps you track daily habits by storing them in a local JSON file.
 #[derive(Parser)]
 #[command(name = "Habit Tracker")]
@@ -22,32 +23,203 @@ struct Cli {
 #[derive(Subcommand)]
 enum Commands {
     /// Add a new habit to track
-    /// 
+    ///
     /// Example: habit add workout
+    /// Pass --goal to track a daily quantity instead of a simple yes/no (e.g. habit add pushups --goal 50)
+    /// By default a habit is due every day; use --on or --every for other schedules.
     Add {
         /// Name of the habit (e.g., "workout", "reading", "meditation")
         name: String,
+
+        /// Daily target for a count-based habit (e.g. 50 pushups, 8 glasses of water)
+        /// If omitted, the habit is a simple done/not-done habit.
+        #[arg(long)]
+        goal: Option<u32>,
+
+        /// Only due on these weekdays, e.g. "mon,wed,fri" (mutually exclusive with --every)
+        #[arg(long)]
+        on: Option<String>,
+
+        /// Only due every N days, e.g. 3 for every third day (mutually exclusive with --on)
+        #[arg(long)]
+        every: Option<u32>,
+
+        /// Shell command that auto-tracks this habit instead of manual `done` calls
+        /// (e.g. "git log --since=midnight --oneline | wc -l")
+        #[arg(long)]
+        auto: Option<String>,
     },
-    
+
     /// Mark a habit as done for today
-    /// 
+    ///
     /// Example: habit done workout
+    /// For count-based habits, --amount adds to today's running total instead of just marking it done.
+    /// Auto-tracked habits reject this; they're updated by `refresh` instead.
     Done {
         /// Name of the habit to mark complete
         name: String,
+
+        /// Amount to add to today's total (count-based habits only; defaults to 1)
+        #[arg(long, default_value_t = 1)]
+        amount: u32,
     },
-    
+
+    /// Re-run the auto-track command for every auto-tracked habit
+    ///
+    /// This also runs implicitly before `stats` so numbers are always current.
+    Refresh,
+
     /// Show statistics for all habits
-    /// 
-    /// Displays total completions and current streak for each habit
-    Stats,
-    
+    ///
+    /// Displays total completions, current/longest streak, completion rate and last-done date
+    Stats {
+        /// "day" shows the usual tabular summary; "month" shows a calendar heatmap per habit
+        #[arg(long, value_enum, default_value_t = StatsView::Day)]
+        view: StatsView,
+
+        /// Size in days of the window used to compute the completion rate
+        #[arg(long, default_value_t = 30)]
+        days: u32,
+    },
+
     /// List all habits
     List,
+
+    /// Show a month calendar grid of completions for one habit
+    ///
+    /// Example: habit calendar workout --month 2026-07
+    Calendar {
+        /// Name of the habit to render
+        name: String,
+
+        /// Month to render as "YYYY-MM" (defaults to the current month)
+        #[arg(long)]
+        month: Option<String>,
+    },
+
+    /// Permanently remove a habit and all of its history
+    Delete {
+        /// Name of the habit to delete
+        name: String,
+    },
+
+    /// Rename a habit, keeping its history
+    Rename {
+        /// Current name of the habit
+        old: String,
+
+        /// New name for the habit
+        new: String,
+    },
+
+    /// Remove today's logged entry for a habit
+    ///
+    /// Use this to correct an accidental `done`.
+    Undo {
+        /// Name of the habit to undo today's entry for
+        name: String,
+    },
+}
+
+/// Which shape of output `stats` should print
+#[derive(Clone, Copy, Debug, clap::ValueEnum)]
+enum StatsView {
+    Day,
+    Month,
+}
+
+/// The kind of habit being tracked
+///
+/// `Bit` habits are a simple yes/no for the day. `Count` habits accumulate
+/// a quantity each day and are considered done once that quantity reaches `goal`.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+enum HabitKind {
+    Bit,
+    Count { goal: u32 },
+}
+
+/// How often a habit is due
+///
+/// `calculate_streak` only looks at dates the schedule says are due; days
+/// that aren't due are skipped entirely rather than breaking the streak.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+enum Schedule {
+    #[default]
+    Daily,
+    Weekly { weekdays: Vec<chrono::Weekday> },
+    EveryNDays { n: u32 },
+}
+
+/// Parse a comma-separated weekday spec like "mon,wed,fri" into `chrono::Weekday`s
+fn parse_weekdays(spec: &str) -> Result<Vec<chrono::Weekday>, String> {
+    spec.split(',')
+        .map(|part| match part.trim().to_lowercase().as_str() {
+            "mon" | "monday" => Ok(chrono::Weekday::Mon),
+            "tue" | "tuesday" => Ok(chrono::Weekday::Tue),
+            "wed" | "wednesday" => Ok(chrono::Weekday::Wed),
+            "thu" | "thursday" => Ok(chrono::Weekday::Thu),
+            "fri" | "friday" => Ok(chrono::Weekday::Fri),
+            "sat" | "saturday" => Ok(chrono::Weekday::Sat),
+            "sun" | "sunday" => Ok(chrono::Weekday::Sun),
+            other => Err(format!("unrecognized weekday '{}'", other)),
+        })
+        .collect()
+}
+
+/// Parse a "YYYY-MM" month spec into the first day of that month, defaulting to the current month
+fn parse_month(month: Option<String>) -> Result<chrono::NaiveDate, String> {
+    match month {
+        Some(spec) => chrono::NaiveDate::parse_from_str(&format!("{}-01", spec), "%Y-%m-%d")
+            .map_err(|_| format!("invalid month '{}', expected YYYY-MM", spec)),
+        None => {
+            let today = chrono::Local::now().date_naive();
+            Ok(chrono::NaiveDate::from_ymd_opt(today.year(), today.month(), 1).unwrap())
+        }
+    }
+}
+
+/// Render a habit's completions for the month starting at `first_of_month` as a 7-column grid
+///
+/// Filled glyphs mark days the goal was reached, light glyphs mark missed due
+/// days, and blanks cover days outside the month, days that aren't due, and future days.
+fn calendar_grid(habit: &Habit, first_of_month: chrono::NaiveDate) -> String {
+    let next_month = if first_of_month.month() == 12 {
+        chrono::NaiveDate::from_ymd_opt(first_of_month.year() + 1, 1, 1).unwrap()
+    } else {
+        chrono::NaiveDate::from_ymd_opt(first_of_month.year(), first_of_month.month() + 1, 1).unwrap()
+    };
+
+    let today = chrono::Local::now().date_naive();
+    let lead_blanks = first_of_month.weekday().num_days_from_monday() as usize;
+
+    let mut cells: Vec<String> = vec!["   ".to_string(); lead_blanks];
+    let mut day = first_of_month;
+    while day < next_month {
+        let date_str = day.format("%Y-%m-%d").to_string();
+        let glyph = if day > today {
+            " "
+        } else if habit.reached_goal(&date_str) {
+            "█"
+        } else if habit.is_due(day) {
+            "·"
+        } else {
+            " "
+        };
+        cells.push(format!(" {} ", glyph));
+        day = day.succ_opt().unwrap();
+    }
+
+    let mut out = String::new();
+    out.push_str("Mon Tue Wed Thu Fri Sat Sun\n");
+    for week in cells.chunks(7) {
+        out.push_str(&week.join(""));
+        out.push('\n');
+    }
+    out
 }
 
 /// Represents a single habit and its completion history
-/// 
+///
 /// The #[derive] attributes automatically implement common traits:
 /// - Serialize/Deserialize: Convert to/from JSON
 /// - Debug: Allow printing with {:?}
@@ -56,60 +228,214 @@ enum Commands {
 struct Habit {
     /// Name of the habit (e.g., "workout")
     name: String,
-    
-    /// List of dates when this habit was completed (format: "YYYY-MM-DD")
-    completions: Vec<String>,
+
+    /// Whether this habit is a plain yes/no or tracks a daily quantity
+    kind: HabitKind,
+
+    /// How often this habit is due; existing habits without this field default to Daily
+    #[serde(default)]
+    schedule: Schedule,
+
+    /// The date this habit was created (format: "YYYY-MM-DD"), used as the
+    /// anchor for `EveryNDays` schedules
+    #[serde(default = "Habit::default_created")]
+    created: String,
+
+    /// Shell command that determines this habit's completion automatically.
+    /// When set, manual `done` is rejected and `refresh` runs the command instead.
+    #[serde(default)]
+    command: Option<String>,
+
+    /// Maps date (format: "YYYY-MM-DD") to the amount logged that day
+    /// For a Bit habit this is just 1 once marked done.
+    completions: HashMap<String, u32>,
 }
 
 impl Habit {
     /// Create a new habit with no completions yet
-    fn new(name: String) -> Self {
+    fn new(name: String, kind: HabitKind, schedule: Schedule, command: Option<String>) -> Self {
         Habit {
             name,
-            completions: Vec::new(),
+            kind,
+            schedule,
+            created: chrono::Local::now().format("%Y-%m-%d").to_string(),
+            command,
+            completions: HashMap::new(),
+        }
+    }
+
+    /// Fallback `created` date for habits loaded from JSON written before this field existed
+    fn default_created() -> String {
+        "1970-01-01".to_string()
+    }
+
+    /// The amount required on a given day for this habit to count as done
+    fn goal(&self) -> u32 {
+        match self.kind {
+            HabitKind::Bit => 1,
+            HabitKind::Count { goal } => goal,
         }
     }
-    
-    /// Calculate the current streak (consecutive days completed)
-    /// 
-    /// Returns the number of consecutive days this habit has been done,
-    /// counting backwards from today.
+
+    /// Whether the given date (format: "YYYY-MM-DD") meets or exceeds the goal
+    fn reached_goal(&self, date: &str) -> bool {
+        self.completions.get(date).copied().unwrap_or(0) >= self.goal()
+    }
+
+    /// Whether this habit is due on the given date according to its schedule
+    fn is_due(&self, date: chrono::NaiveDate) -> bool {
+        match &self.schedule {
+            Schedule::Daily => true,
+            Schedule::Weekly { weekdays } => !weekdays.is_empty() && weekdays.contains(&date.weekday()),
+            Schedule::EveryNDays { n } => {
+                if *n == 0 {
+                    return true;
+                }
+                let created = chrono::NaiveDate::parse_from_str(&self.created, "%Y-%m-%d")
+                    .unwrap_or(date);
+                let days_since = (date - created).num_days();
+                days_since >= 0 && days_since % *n as i64 == 0
+            }
+        }
+    }
+
+    /// Whether this habit's schedule can never be due, e.g. a `Weekly` schedule
+    /// with no weekdays selected. Guards the backward-walking streak loops
+    /// below from searching forever for a due date that doesn't exist.
+    fn never_due(&self) -> bool {
+        matches!(&self.schedule, Schedule::Weekly { weekdays } if weekdays.is_empty())
+    }
+
+    /// Calculate the current streak (consecutive due dates completed)
+    ///
+    /// Walks backward from the most recent due date on or before today,
+    /// requiring a completion at each due date. Dates that aren't due are
+    /// skipped entirely; the streak ends at the first missed due date.
     fn calculate_streak(&self) -> usize {
-        if self.completions.is_empty() {
+        if self.never_due() {
             return 0;
         }
-        
-        // Sort completions in reverse chronological order (newest first)
-        let mut sorted_completions = self.completions.clone();
-        sorted_completions.sort();
-        sorted_completions.reverse();
-        
+
         let mut streak = 0;
         let mut current_date = chrono::Local::now().date_naive();
-        
-        // Count consecutive days going backwards from today
-        for completion in sorted_completions {
-            let completion_date = chrono::NaiveDate::parse_from_str(&completion, "%Y-%m-%d");
-            
-            if let Ok(comp_date) = completion_date {
-                // Check if this completion matches our expected date
-                if comp_date == current_date {
-                    streak += 1;
-                    // Move to the previous day
+
+        while !self.is_due(current_date) {
+            current_date = current_date.pred_opt().unwrap();
+        }
+
+        loop {
+            let date_str = current_date.format("%Y-%m-%d").to_string();
+
+            if self.reached_goal(&date_str) {
+                streak += 1;
+                current_date = current_date.pred_opt().unwrap();
+                while !self.is_due(current_date) {
+                    // An EveryNDays schedule has no due dates before `created`
+                    if matches!(self.schedule, Schedule::EveryNDays { .. })
+                        && current_date < self.created_date()
+                    {
+                        return streak;
+                    }
                     current_date = current_date.pred_opt().unwrap();
-                } else {
-                    // Streak is broken
-                    break;
                 }
+            } else {
+                break;
             }
         }
-        
+
         streak
     }
+
+    /// Parse the `created` field, falling back to today if it's missing or malformed
+    fn created_date(&self) -> chrono::NaiveDate {
+        chrono::NaiveDate::parse_from_str(&self.created, "%Y-%m-%d")
+            .unwrap_or_else(|_| chrono::Local::now().date_naive())
+    }
+
+    /// The longest run of consecutive due dates this habit has ever reached its goal on
+    fn longest_streak(&self) -> usize {
+        if self.never_due() {
+            return 0;
+        }
+
+        let earliest_completion = self
+            .completions
+            .keys()
+            .filter_map(|d| chrono::NaiveDate::parse_from_str(d, "%Y-%m-%d").ok())
+            .min();
+        let start = match earliest_completion {
+            Some(d) => d.min(self.created_date()),
+            None => self.created_date(),
+        };
+        let today = chrono::Local::now().date_naive();
+
+        let mut longest = 0;
+        let mut current = 0;
+        let mut day = start;
+        while day <= today {
+            if self.is_due(day) {
+                let date_str = day.format("%Y-%m-%d").to_string();
+                if self.reached_goal(&date_str) {
+                    current += 1;
+                    longest = longest.max(current);
+                } else {
+                    current = 0;
+                }
+            }
+            day = day.succ_opt().unwrap();
+        }
+
+        longest
+    }
+
+    /// Completion rate over the last `days` days, as a percentage of due days reached
+    fn completion_rate(&self, days: u32) -> f64 {
+        // Cap the window so a huge --days value (up to u32::MAX) can't overflow
+        // NaiveDate's representable range or force an enormous day-by-day scan.
+        let days = days.clamp(1, 36_500);
+        let today = chrono::Local::now().date_naive();
+        let start = today - chrono::Duration::days(days as i64 - 1);
+
+        let mut due_days = 0;
+        let mut done_days = 0;
+        let mut day = start;
+        while day <= today {
+            if self.is_due(day) {
+                due_days += 1;
+                let date_str = day.format("%Y-%m-%d").to_string();
+                if self.reached_goal(&date_str) {
+                    done_days += 1;
+                }
+            }
+            day = day.succ_opt().unwrap();
+        }
+
+        if due_days == 0 {
+            0.0
+        } else {
+            (done_days as f64 / due_days as f64) * 100.0
+        }
+    }
+
+    /// The most recent date the goal was reached, if any
+    fn last_done(&self) -> Option<String> {
+        self.completions
+            .keys()
+            .filter(|date| self.reached_goal(date))
+            .max()
+            .cloned()
+    }
+
+    /// Number of days this habit actually reached its goal, as opposed to
+    /// `completions.len()` which also counts days merely logged below goal
+    /// (e.g. a partial count, or an auto-refresh that recorded 0).
+    fn total_completions(&self) -> usize {
+        self.completions.keys().filter(|date| self.reached_goal(date)).count()
+    }
 }
 
 /// The main data structure holding all habits
-/// 
+///
 /// We use a HashMap for O(1) lookup by habit name
 #[derive(Serialize, Deserialize, Debug)]
 struct HabitTracker {
@@ -125,9 +451,9 @@ impl HabitTracker {
             habits: HashMap::new(),
         }
     }
-    
+
     /// Load habits from the JSON file
-    /// 
+    ///
     /// If the file doesn't exist, return a new empty tracker.
     /// This is called every time we run a command.
     fn load() -> Result<Self, Box<dyn std::error::Error>> {
@@ -136,141 +462,381 @@ impl HabitTracker {
             // File doesn't exist yet - that's okay, return empty tracker
             return Ok(HabitTracker::new());
         }
-        
+
         // Read the entire file into a String
         let data = fs::read_to_string(DATA_FILE)?;
-        
+
         // Parse the JSON string into our HabitTracker struct
         // serde_json does all the heavy lifting here
         let tracker: HabitTracker = serde_json::from_str(&data)?;
-        
+
         Ok(tracker)
     }
-    
+
     /// Save habits to the JSON file
-    /// 
-    /// This writes the entire HashMap to disk as formatted JSON.
+    ///
+    /// Writes to a temporary file and atomically renames it over `DATA_FILE`,
+    /// so an interrupted write can't leave the user's history half-written.
     /// Called after any command that modifies the data.
     fn save(&self) -> Result<(), Box<dyn std::error::Error>> {
         // Convert our struct to a pretty-printed JSON string
         // The "pretty" formatting makes it human-readable with indentation
         let json = serde_json::to_string_pretty(&self)?;
-        
-        // Write the JSON string to the file (creates or overwrites)
-        fs::write(DATA_FILE, json)?;
-        
+
+        let tmp_file = format!("{}.tmp", DATA_FILE);
+        fs::write(&tmp_file, json)?;
+        fs::rename(&tmp_file, DATA_FILE)?;
+
         Ok(())
     }
-    
+
     /// Add a new habit to track
-    fn add_habit(&mut self, name: String) {
+    fn add_habit(
+        &mut self,
+        name: String,
+        goal: Option<u32>,
+        on: Option<String>,
+        every: Option<u32>,
+        auto: Option<String>,
+    ) {
         // Check if habit already exists
         if self.habits.contains_key(&name) {
             println!("⚠️  Habit '{}' already exists!", name);
             return;
         }
-        
+
+        let schedule = match (on, every) {
+            (Some(_), Some(_)) => {
+                println!("❌ Use either --on or --every, not both");
+                return;
+            }
+            (Some(spec), None) => match parse_weekdays(&spec) {
+                Ok(weekdays) => Schedule::Weekly { weekdays },
+                Err(e) => {
+                    println!("❌ Invalid --on value: {}", e);
+                    return;
+                }
+            },
+            (None, Some(n)) => Schedule::EveryNDays { n },
+            (None, None) => Schedule::Daily,
+        };
+
         // Create new habit and add to HashMap
-        let habit = Habit::new(name.clone());
+        let kind = match goal {
+            Some(goal) => HabitKind::Count { goal },
+            None => HabitKind::Bit,
+        };
+        let habit = Habit::new(name.clone(), kind, schedule, auto);
         self.habits.insert(name.clone(), habit);
-        
+
         println!("✅ Added habit: '{}'", name);
     }
-    
-    /// Mark a habit as completed for today
-    fn mark_done(&mut self, name: String) {
+
+    /// Log progress on a habit for today
+    ///
+    /// For a Bit habit `amount` is ignored and today's entry is set to 1. For a
+    /// Count habit this adds `amount` to whatever has already been logged today.
+    fn mark_done(&mut self, name: String, amount: u32) {
         // Try to get the habit from the HashMap
         match self.habits.get_mut(&name) {
             Some(habit) => {
+                if habit.command.is_some() {
+                    println!(
+                        "❌ '{}' is auto-tracked and can't be marked done manually. Use 'habit refresh' instead.",
+                        name
+                    );
+                    return;
+                }
+
                 // Get today's date
                 let today = chrono::Local::now().format("%Y-%m-%d").to_string();
-                
-                // Check if already marked done today
-                if habit.completions.contains(&today) {
-                    println!("ℹ️  You already completed '{}' today!", name);
-                    return;
+
+                let total = {
+                    let entry = habit.completions.entry(today.clone()).or_insert(0);
+                    *entry = match habit.kind {
+                        HabitKind::Bit => 1,
+                        HabitKind::Count { .. } => entry.saturating_add(amount),
+                    };
+                    *entry
+                };
+
+                if habit.reached_goal(&today) {
+                    println!("🎉 Marked '{}' as done for today!", name);
+                } else {
+                    let remaining = habit.goal() - total;
+                    println!(
+                        "📈 Logged {} for '{}' today ({} more to reach goal)",
+                        total, name, remaining
+                    );
                 }
-                
-                // Add today to the completions list
-                habit.completions.push(today);
-                println!("🎉 Marked '{}' as done for today!", name);
             }
             None => {
                 println!("❌ Habit '{}' not found. Add it first with 'habit add {}'", name, name);
             }
         }
     }
-    
+
+    /// Re-run each auto-tracked habit's command and record today's result
+    ///
+    /// A non-zero exit status or empty stdout counts as "not done" (0). Numeric
+    /// stdout is recorded as today's count. A habit whose command fails to run
+    /// is reported but doesn't stop the rest of the refresh.
+    fn refresh_auto_habits(&mut self) {
+        let today = chrono::Local::now().format("%Y-%m-%d").to_string();
+
+        for habit in self.habits.values_mut() {
+            let Some(command) = habit.command.clone() else {
+                continue;
+            };
+
+            match std::process::Command::new("sh").arg("-c").arg(&command).output() {
+                Ok(output) => {
+                    let stdout = String::from_utf8_lossy(&output.stdout);
+                    let count = if output.status.success() {
+                        stdout.trim().parse::<u32>().unwrap_or(0)
+                    } else {
+                        0
+                    };
+                    habit.completions.insert(today.clone(), count);
+                }
+                Err(e) => {
+                    println!("⚠️  Couldn't run auto-check for '{}': {}", habit.name, e);
+                }
+            }
+        }
+    }
+
     /// Display statistics for all habits
-    fn show_stats(&self) {
+    fn show_stats(&self, days: u32) {
         if self.habits.is_empty() {
             println!("No habits tracked yet. Add one with 'habit add <name>'");
             return;
         }
-        
+
         println!("\n📊 Habit Statistics\n");
-        println!("{:<20} {:<15} {:<15}", "Habit", "Total Done", "Current Streak");
-        println!("{}", "-".repeat(50));
-        
+        println!(
+            "{:<16} {:<8} {:<10} {:<10} {:<12} {:<12} {:<10}",
+            "Habit", "Total", "Streak", "Longest", format!("Rate({}d)", days), "Last Done", "Today"
+        );
+        println!("{}", "-".repeat(82));
+
+        let today = chrono::Local::now().format("%Y-%m-%d").to_string();
+        let mut total_completions = 0;
+        let mut best_streak = 0;
+        let mut best_streak_habit = String::new();
+
         // Iterate through all habits
         for (name, habit) in &self.habits {
-            let total = habit.completions.len();
+            let total = habit.total_completions();
             let streak = habit.calculate_streak();
-            
-            println!("{:<20} {:<15} {:<15}", name, total, format!("{} days", streak));
+            let longest = habit.longest_streak();
+            let rate = habit.completion_rate(days);
+            let last_done = habit.last_done().unwrap_or_else(|| "never".to_string());
+            let logged_today = habit.completions.get(&today).copied().unwrap_or(0);
+            let today_str = match habit.kind {
+                HabitKind::Bit => if logged_today >= 1 { "done".to_string() } else { "not yet".to_string() },
+                HabitKind::Count { goal } => {
+                    if logged_today >= goal {
+                        format!("{}/{}", logged_today, goal)
+                    } else {
+                        format!("{}/{} ({} to go)", logged_today, goal, goal - logged_today)
+                    }
+                }
+            };
+
+            println!(
+                "{:<16} {:<8} {:<10} {:<10} {:<12} {:<12} {:<10}",
+                name,
+                total,
+                format!("{}d", streak),
+                format!("{}d", longest),
+                format!("{:.0}%", rate),
+                last_done,
+                today_str
+            );
+
+            total_completions += total;
+            if streak > best_streak {
+                best_streak = streak;
+                best_streak_habit = name.clone();
+            }
         }
-        
+
+        println!("{}", "-".repeat(82));
+        println!(
+            "Tracking {} habit(s), {} total completions logged.",
+            self.habits.len(),
+            total_completions
+        );
+        if best_streak > 0 {
+            println!("🔥 Best current streak: {} days ({})", best_streak, best_streak_habit);
+        }
+
         println!();
     }
-    
+
+    /// Show a calendar heatmap for every habit, for the current month
+    fn show_stats_calendar(&self) {
+        if self.habits.is_empty() {
+            println!("No habits tracked yet. Add one with 'habit add <name>'");
+            return;
+        }
+
+        let first_of_month = parse_month(None).unwrap();
+        for (name, habit) in &self.habits {
+            println!("\n📅 {} — {}\n", name, first_of_month.format("%B %Y"));
+            print!("{}", calendar_grid(habit, first_of_month));
+        }
+        println!();
+    }
+
+    /// Show a single habit's completions for a month as a 7-column weekday grid
+    fn show_calendar(&self, name: &str, month: Option<String>) {
+        let habit = match self.habits.get(name) {
+            Some(habit) => habit,
+            None => {
+                println!("❌ Habit '{}' not found.", name);
+                return;
+            }
+        };
+
+        let first_of_month = match parse_month(month) {
+            Ok(date) => date,
+            Err(e) => {
+                println!("❌ {}", e);
+                return;
+            }
+        };
+
+        println!("\n📅 {} — {}\n", name, first_of_month.format("%B %Y"));
+        print!("{}", calendar_grid(habit, first_of_month));
+        println!();
+    }
+
     /// List all tracked habits
     fn list_habits(&self) {
         if self.habits.is_empty() {
             println!("No habits tracked yet. Add one with 'habit add <name>'");
             return;
         }
-        
+
         println!("\n📝 Your Habits:\n");
         for name in self.habits.keys() {
             println!("  • {}", name);
         }
         println!();
     }
+
+    /// Permanently remove a habit and all of its history
+    fn delete_habit(&mut self, name: &str) {
+        match self.habits.remove(name) {
+            Some(_) => println!("🗑️  Deleted habit '{}'", name),
+            None => println!("❌ Habit '{}' not found.", name),
+        }
+    }
+
+    /// Rename a habit, keeping its completion history
+    fn rename_habit(&mut self, old: &str, new: &str) {
+        if !self.habits.contains_key(old) {
+            println!("❌ Habit '{}' not found.", old);
+            return;
+        }
+        if self.habits.contains_key(new) {
+            println!("⚠️  Habit '{}' already exists!", new);
+            return;
+        }
+
+        let mut habit = self.habits.remove(old).unwrap();
+        habit.name = new.to_string();
+        self.habits.insert(new.to_string(), habit);
+
+        println!("✏️  Renamed '{}' to '{}'", old, new);
+    }
+
+    /// Remove today's logged entry for a habit, if any
+    fn undo_today(&mut self, name: &str) {
+        match self.habits.get_mut(name) {
+            Some(habit) => {
+                let today = chrono::Local::now().format("%Y-%m-%d").to_string();
+                if habit.completions.remove(&today).is_some() {
+                    println!("↩️  Undid today's entry for '{}'", name);
+                } else {
+                    println!("ℹ️  Nothing logged for '{}' today.", name);
+                }
+            }
+            None => println!("❌ Habit '{}' not found.", name),
+        }
+    }
 }
 
 fn main() {
     // Parse command-line arguments using clap
     let cli = Cli::parse();
-    
+
     // Load existing habits from file (or create new tracker if file doesn't exist)
     let mut tracker = HabitTracker::load().unwrap_or_else(|err| {
         eprintln!("Error loading habits: {}", err);
         HabitTracker::new()
     });
-    
+
     // Execute the appropriate command
     match cli.command {
-        Commands::Add { name } => {
-            tracker.add_habit(name);
+        Commands::Add { name, goal, on, every, auto } => {
+            tracker.add_habit(name, goal, on, every, auto);
             // Save changes to disk
             if let Err(e) = tracker.save() {
                 eprintln!("Error saving habits: {}", e);
             }
         }
-        Commands::Done { name } => {
-            tracker.mark_done(name);
+        Commands::Done { name, amount } => {
+            tracker.mark_done(name, amount);
             // Save changes to disk
             if let Err(e) = tracker.save() {
                 eprintln!("Error saving habits: {}", e);
             }
         }
-        Commands::Stats => {
-            // No need to save for read-only operations
-            tracker.show_stats();
+        Commands::Refresh => {
+            tracker.refresh_auto_habits();
+            if let Err(e) = tracker.save() {
+                eprintln!("Error saving habits: {}", e);
+            }
+        }
+        Commands::Stats { view, days } => {
+            // Auto-tracked habits should reflect today's command output before we report on them
+            tracker.refresh_auto_habits();
+            if let Err(e) = tracker.save() {
+                eprintln!("Error saving habits: {}", e);
+            }
+            match view {
+                StatsView::Day => tracker.show_stats(days),
+                StatsView::Month => tracker.show_stats_calendar(),
+            }
         }
         Commands::List => {
             // No need to save for read-only operations
             tracker.list_habits();
         }
+        Commands::Calendar { name, month } => {
+            // No need to save for read-only operations
+            tracker.show_calendar(&name, month);
+        }
+        Commands::Delete { name } => {
+            tracker.delete_habit(&name);
+            if let Err(e) = tracker.save() {
+                eprintln!("Error saving habits: {}", e);
+            }
+        }
+        Commands::Rename { old, new } => {
+            tracker.rename_habit(&old, &new);
+            if let Err(e) = tracker.save() {
+                eprintln!("Error saving habits: {}", e);
+            }
+        }
+        Commands::Undo { name } => {
+            tracker.undo_today(&name);
+            if let Err(e) = tracker.save() {
+                eprintln!("Error saving habits: {}", e);
+            }
+        }
     }
-}
\ No newline at end of file
+}